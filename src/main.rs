@@ -1,29 +1,181 @@
-use chrono::{Local, Timelike};
+use chrono::{Local, Timelike, Utc};
 use crossterm::{
     event::{self, Event, KeyCode, KeyEvent},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{
-    Terminal,
+    Frame, Terminal,
     backend::CrosstermBackend,
-    layout::Rect,
-    style::{Color, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{LineGauge, Paragraph},
+};
+use tui_big_text::{BigText, PixelSize};
+use std::{
+    env,
+    io::{self, Write, stdout},
+    time::{Duration, Instant},
 };
-use std::{env, io, io::stdout, time::Duration};
 
 #[derive(Clone, Copy)]
 enum CellType {
     Empty,
     Circle,
     Numeral,
+    MinuteTick,
+    HourTick,
     HourHand,
     MinuteHand,
     SecondHand,
 }
 
+/// Countdown timer state threaded through the render loop.
+struct Timer {
+    start: Instant,
+    total: Duration,
+    /// Set once the alarm has fired so the bell and notifier run only once.
+    notified: bool,
+}
+
+/// The wall-clock zone the faces should display.
+enum Zone {
+    Local,
+    Utc,
+    Tz(chrono_tz::Tz),
+}
+
+impl Zone {
+    /// Current `(hour, minute, second, subsecond-nanos)` in this zone.
+    fn now(&self) -> (u32, u32, u32, u32) {
+        match self {
+            Zone::Local => parts(Local::now()),
+            Zone::Utc => parts(Utc::now()),
+            Zone::Tz(tz) => parts(Utc::now().with_timezone(tz)),
+        }
+    }
+}
+
+/// Decompose any `Timelike` value into the fields the faces need.
+fn parts<T: Timelike>(t: T) -> (u32, u32, u32, u32) {
+    (t.hour(), t.minute(), t.second(), t.nanosecond())
+}
+
+/// Stopwatch state: a face that sweeps from zero, with pause support and laps.
+struct Stopwatch {
+    /// When the current running segment began, or `None` while paused.
+    running_since: Option<Instant>,
+    /// Elapsed time banked from previous running segments.
+    accumulated: Duration,
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    /// Start a fresh, running stopwatch.
+    fn new() -> Self {
+        Stopwatch {
+            running_since: Some(Instant::now()),
+            accumulated: Duration::ZERO,
+            laps: Vec::new(),
+        }
+    }
+
+    /// Total elapsed time including the in-flight running segment.
+    fn elapsed(&self) -> Duration {
+        self.accumulated + self.running_since.map(|t| t.elapsed()).unwrap_or_default()
+    }
+
+    /// Toggle between running and paused, banking the elapsed segment on pause.
+    fn toggle(&mut self) {
+        if let Some(t) = self.running_since.take() {
+            self.accumulated += t.elapsed();
+        } else {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    /// Zero the stopwatch, preserving the current run/pause state.
+    fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.running_since = self.running_since.map(|_| Instant::now());
+        self.laps.clear();
+    }
+
+    /// Record the current elapsed time as a lap split.
+    fn lap(&mut self) {
+        self.laps.push(self.elapsed());
+    }
+}
+
+/// Map an elapsed duration onto `(hour, minute, second, nanos)` so the existing
+/// hand-drawing math sweeps from zero rather than wall-clock time.
+fn elapsed_parts(d: Duration) -> (u32, u32, u32, u32) {
+    let total = d.as_secs();
+    let h = ((total / 3600) % 12) as u32;
+    let m = ((total % 3600) / 60) as u32;
+    let s = (total % 60) as u32;
+    (h, m, s, d.subsec_nanos())
+}
+
+/// Look up the value following a `--flag` argument, if present.
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1).cloned())
+}
+
+/// Parse a human duration like `25m`, `90s`, or `1h30m` into a `Duration`.
+/// A bare number is interpreted as seconds.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let mut total = 0u64;
+    let mut num = String::new();
+    let mut saw_unit = false;
+    for ch in s.chars() {
+        if ch.is_ascii_digit() {
+            num.push(ch);
+        } else {
+            let n: u64 = num.parse().ok()?;
+            num.clear();
+            total += match ch {
+                'h' => n * 3600,
+                'm' => n * 60,
+                's' => n,
+                _ => return None,
+            };
+            saw_unit = true;
+        }
+    }
+    if !num.is_empty() {
+        total += num.parse::<u64>().ok()?;
+        saw_unit = true;
+    }
+    if saw_unit {
+        Some(Duration::from_secs(total))
+    } else {
+        None
+    }
+}
+
+/// Format a duration as `MM:SS`, promoting to `HH:MM:SS` past an hour.
+fn format_hms(d: Duration) -> String {
+    let total = d.as_secs();
+    let (h, m, s) = (total / 3600, (total % 3600) / 60, total % 60);
+    if h > 0 {
+        format!("{h:02}:{m:02}:{s:02}")
+    } else {
+        format!("{m:02}:{s:02}")
+    }
+}
+
+/// Best-effort desktop notification; silently ignored when unavailable.
+fn notify(summary: &str, body: &str) {
+    let _ = std::process::Command::new("notify-send")
+        .arg(summary)
+        .arg(body)
+        .status();
+}
+
 /// Bresenham line algorithm to draw a line from (x0, y0) to (x1, y1)
 fn draw_line(
     grid: &mut [Vec<(char, CellType)>],
@@ -61,8 +213,9 @@ fn draw_line(
     }
 }
 
-/// Generate the ASCII clock face
-fn draw_clock(area: Rect, use_color: bool) -> Vec<Line<'static>> {
+/// Build the character/cell grid for the clock face. Shared by the terminal
+/// renderer and the `.txt` exporter so the two stay in sync.
+fn build_grid(area: Rect, time: (u32, u32, u32, u32)) -> Vec<Vec<(char, CellType)>> {
     let width = area.width as i32;
     let height = area.height as i32;
     let mut grid = vec![vec![(' ', CellType::Empty); width as usize]; height as usize];
@@ -86,6 +239,35 @@ fn draw_clock(area: Rect, use_color: bool) -> Vec<Line<'static>> {
         }
     }
 
+    // Minute track: 60 ticks around the dial with the 12 hour positions drawn
+    // heavier, mirroring a real face. Drop to a 12-tick ring on areas too small
+    // for the full track to stay legible.
+    let tick_rad = radius as f64 * 0.92;
+    if radius >= 8 {
+        for i in 0..60 {
+            let angle = i as f64 * std::f64::consts::TAU / 60.0;
+            let x = cx + (angle.sin() * tick_rad) as i32;
+            let y = cy - (angle.cos() * tick_rad * y_scale) as i32;
+            if y < 0 || y >= height || x < 0 || x >= width {
+                continue;
+            }
+            if i % 5 == 0 {
+                grid[y as usize][x as usize] = ('+', CellType::HourTick);
+            } else if matches!(grid[y as usize][x as usize].1, CellType::Empty | CellType::Circle) {
+                grid[y as usize][x as usize] = ('.', CellType::MinuteTick);
+            }
+        }
+    } else {
+        for i in 0..12 {
+            let angle = i as f64 * std::f64::consts::TAU / 12.0;
+            let x = cx + (angle.sin() * tick_rad) as i32;
+            let y = cy - (angle.cos() * tick_rad * y_scale) as i32;
+            if y >= 0 && y < height && x >= 0 && x < width {
+                grid[y as usize][x as usize] = ('|', CellType::HourTick);
+            }
+        }
+    }
+
     // Roman numerals for hours (0=12 at top)
     let romans = [
         "XII", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI",
@@ -117,22 +299,12 @@ fn draw_clock(area: Rect, use_color: bool) -> Vec<Line<'static>> {
                 }
             }
         }
-    } else {
-        // Fallback to ticks on narrow terminals
-        for i in 0..12 {
-            let angle = i as f64 * std::f64::consts::TAU / 12.0;
-            let x = cx + (angle.sin() * (radius as f64 * 0.92)) as i32;
-            let y = cy - (angle.cos() * (radius as f64 * 0.92) * y_scale) as i32;
-            if y >= 0 && y < height && x >= 0 && x < width {
-                grid[y as usize][x as usize] = ('|', CellType::Numeral);
-            }
-        }
     }
 
-    let now = Local::now();
-    let secs = now.second() as f64 + (now.timestamp_subsec_nanos() as f64 / 1_000_000_000.0);
-    let mins = now.minute() as f64 + secs / 60.0;
-    let hours = (now.hour() % 12) as f64 + mins / 60.0;
+    let (hour, minute, second, nanos) = time;
+    let secs = second as f64 + (nanos as f64 / 1_000_000_000.0);
+    let mins = minute as f64 + secs / 60.0;
+    let hours = (hour % 12) as f64 + mins / 60.0;
 
     let second_angle = (secs / 60.0) * std::f64::consts::TAU;
     let minute_angle = (mins / 60.0) * std::f64::consts::TAU;
@@ -158,8 +330,15 @@ fn draw_clock(area: Rect, use_color: bool) -> Vec<Line<'static>> {
     let sy = cy - (second_angle.cos() * second_length as f64 * y_scale) as i32;
     draw_line(&mut grid, cx, cy, sx, sy, '.', CellType::SecondHand);
 
-    // Convert grid to styled lines
-    grid.into_iter()
+    grid
+}
+
+/// Generate the ASCII clock face for the supplied `(hour, minute, second,
+/// nanos)` time, so callers control the displayed zone.
+fn draw_clock(area: Rect, time: (u32, u32, u32, u32), use_color: bool) -> Vec<Line<'static>> {
+    // Convert the shared grid to styled lines
+    build_grid(area, time)
+        .into_iter()
         .map(|row| {
             let spans: Vec<Span> = row
                 .into_iter()
@@ -169,6 +348,8 @@ fn draw_clock(area: Rect, use_color: bool) -> Vec<Line<'static>> {
                             CellType::Empty => Style::default(),
                             CellType::Circle => Style::default().fg(Color::Cyan),
                             CellType::Numeral => Style::default().fg(Color::Yellow),
+                            CellType::MinuteTick => Style::default().fg(Color::DarkGray),
+                            CellType::HourTick => Style::default().fg(Color::White),
                             CellType::HourHand => Style::default().fg(Color::Green),
                             CellType::MinuteHand => Style::default().fg(Color::Blue),
                             CellType::SecondHand => Style::default().fg(Color::Red),
@@ -184,8 +365,259 @@ fn draw_clock(area: Rect, use_color: bool) -> Vec<Line<'static>> {
         .collect()
 }
 
+/// SVG colour for each cell type, mirroring the terminal palette.
+fn svg_color(cell: CellType) -> &'static str {
+    match cell {
+        CellType::Empty => "none",
+        CellType::Circle => "cyan",
+        CellType::Numeral => "gold",
+        CellType::MinuteTick => "gray",
+        CellType::HourTick => "white",
+        CellType::HourHand => "green",
+        CellType::MinuteHand => "blue",
+        CellType::SecondHand => "red",
+    }
+}
+
+/// Dump the clock grid as plain ASCII text, one row per line.
+fn export_txt(area: Rect, time: (u32, u32, u32, u32)) -> String {
+    let mut out = String::new();
+    for row in build_grid(area, time) {
+        for (ch, _) in row {
+            out.push(ch);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render the clock face as a standalone SVG document scaled to `size`, using
+/// the same hand-angle math and lengths as `build_grid` and the per-cell
+/// colour palette. It draws straight from the time, not from the grid.
+fn export_svg(time: (u32, u32, u32, u32), size: f64) -> String {
+    use std::f64::consts::TAU;
+
+    let (hour, minute, second, nanos) = time;
+    let secs = second as f64 + nanos as f64 / 1_000_000_000.0;
+    let mins = minute as f64 + secs / 60.0;
+    let hours = (hour % 12) as f64 + mins / 60.0;
+
+    let second_angle = (secs / 60.0) * TAU;
+    let minute_angle = (mins / 60.0) * TAU;
+    let hour_angle = (hours / 12.0) * TAU;
+
+    let c = size / 2.0;
+    let r = size * 0.45;
+
+    let mut s = String::new();
+    s.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {size} {size}\">\n"
+    ));
+    s.push_str(&format!(
+        "<rect width=\"{size}\" height=\"{size}\" fill=\"black\"/>\n"
+    ));
+    s.push_str(&format!(
+        "<circle cx=\"{c}\" cy=\"{c}\" r=\"{r}\" fill=\"none\" stroke=\"{}\"/>\n",
+        svg_color(CellType::Circle)
+    ));
+
+    // Minute track, heavier at the 12 hour positions.
+    for i in 0..60 {
+        let angle = i as f64 * TAU / 60.0;
+        let inner = if i % 5 == 0 { r - size * 0.05 } else { r - size * 0.025 };
+        let (x1, y1) = (c + angle.sin() * inner, c - angle.cos() * inner);
+        let (x2, y2) = (c + angle.sin() * r, c - angle.cos() * r);
+        let col = if i % 5 == 0 {
+            svg_color(CellType::HourTick)
+        } else {
+            svg_color(CellType::MinuteTick)
+        };
+        s.push_str(&format!(
+            "<line x1=\"{x1:.1}\" y1=\"{y1:.1}\" x2=\"{x2:.1}\" y2=\"{y2:.1}\" stroke=\"{col}\"/>\n"
+        ));
+    }
+
+    // Roman numerals (0=12 at top).
+    let romans = [
+        "XII", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI",
+    ];
+    let font = size * 0.05;
+    for (i, numeral) in romans.iter().enumerate() {
+        let angle = i as f64 * TAU / 12.0;
+        let rp = r * 0.82;
+        let x = c + angle.sin() * rp;
+        let y = c - angle.cos() * rp;
+        s.push_str(&format!(
+            "<text x=\"{x:.1}\" y=\"{y:.1}\" fill=\"{}\" text-anchor=\"middle\" \
+             dominant-baseline=\"middle\" font-size=\"{font:.1}\">{numeral}</text>\n",
+            svg_color(CellType::Numeral)
+        ));
+    }
+
+    // Hands, projected from the same lengths used by the ASCII face.
+    for (angle, frac, cell) in [
+        (hour_angle, 0.45, CellType::HourHand),
+        (minute_angle, 0.75, CellType::MinuteHand),
+        (second_angle, 0.9, CellType::SecondHand),
+    ] {
+        let len = r * frac;
+        let x = c + angle.sin() * len;
+        let y = c - angle.cos() * len;
+        s.push_str(&format!(
+            "<line x1=\"{c:.1}\" y1=\"{c:.1}\" x2=\"{x:.1}\" y2=\"{y:.1}\" stroke=\"{}\"/>\n",
+            svg_color(cell)
+        ));
+    }
+
+    s.push_str("</svg>\n");
+    s
+}
+
+/// Format the time as `HH:MM:SS`, honouring the 12/24-hour choice.
+fn format_digital(h: u32, m: u32, s: u32, twelve_hour: bool) -> String {
+    if twelve_hour {
+        let hour12 = if h % 12 == 0 { 12 } else { h % 12 };
+        format!("{hour12:02}:{m:02}:{s:02}")
+    } else {
+        format!("{h:02}:{m:02}:{s:02}")
+    }
+}
+
+/// Render the supplied time as large block glyphs in its own layout region.
+fn draw_digital(f: &mut Frame, area: Rect, time: (u32, u32, u32, u32), twelve_hour: bool, use_color: bool) {
+    let style = if use_color {
+        Style::default().fg(Color::Magenta)
+    } else {
+        Style::default()
+    };
+    let big = BigText::builder()
+        .pixel_size(PixelSize::Quadrant)
+        .alignment(Alignment::Center)
+        .style(style)
+        .lines(vec![Line::from(format_digital(
+            time.0, time.1, time.2, twelve_hour,
+        ))])
+        .build();
+    f.render_widget(big, area);
+}
+
+/// Render the stopwatch readout and scrolling lap list beside the dial.
+fn draw_stopwatch(f: &mut Frame, area: Rect, sw: &Stopwatch, use_color: bool) {
+    let status = if sw.running_since.is_some() {
+        ""
+    } else {
+        " (paused)"
+    };
+    let header = if use_color {
+        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("{}{status}", format_hms(sw.elapsed())),
+            header,
+        )),
+        Line::from("── laps ──"),
+    ];
+    for (i, lap) in sw.laps.iter().enumerate() {
+        lines.push(Line::from(format!("{:>2}. {}", i + 1, format_hms(*lap))));
+    }
+
+    // Scroll by keeping only the tail that fits the available height.
+    let cap = area.height as usize;
+    if cap > 0 && lines.len() > cap {
+        lines = lines.split_off(lines.len() - cap);
+    }
+    f.render_widget(Paragraph::new(lines), area);
+}
+
+/// Render the countdown gauge and remaining-time readout beneath the face.
+fn draw_timer(f: &mut Frame, area: Rect, timer: &Timer, use_color: bool) {
+    let elapsed = timer.start.elapsed();
+    let remaining = timer.total.saturating_sub(elapsed);
+
+    if remaining.is_zero() {
+        // Blink the banner once a second off the wall clock: lit for the
+        // first half of each second, dimmed for the second half.
+        let on = Local::now().timestamp_subsec_millis() < 500;
+        let style = if on {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let banner = Paragraph::new(Line::from(Span::styled("*** TIME'S UP ***", style)))
+            .alignment(Alignment::Center);
+        f.render_widget(banner, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .split(area);
+
+    let ratio = (elapsed.as_secs_f64() / timer.total.as_secs_f64()).clamp(0.0, 1.0);
+    let filled = if use_color {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default()
+    };
+    let gauge = LineGauge::default()
+        .ratio(ratio)
+        .filled_style(filled)
+        .label(format!("{} left", format_hms(remaining)));
+    f.render_widget(gauge, chunks[0]);
+}
+
 fn main() -> io::Result<()> {
-    let use_color = env::args().any(|arg| arg == "--color");
+    let args: Vec<String> = env::args().collect();
+    let use_color = args.iter().any(|arg| arg == "--color");
+    let twelve_hour = args.iter().any(|arg| arg == "--12h");
+    let mut digital = args.iter().any(|arg| arg == "--digital");
+    let discrete = args.iter().any(|arg| arg == "--discrete");
+    let mut stopwatch = args
+        .iter()
+        .any(|arg| arg == "--stopwatch")
+        .then(Stopwatch::new);
+
+    // Resolve the display zone once, up front, so an unknown name fails before
+    // we touch the terminal.
+    let zone = if args.iter().any(|a| a == "--utc") {
+        Zone::Utc
+    } else if let Some(name) = flag_value(&args, "--tz") {
+        match name.parse::<chrono_tz::Tz>() {
+            Ok(tz) => Zone::Tz(tz),
+            Err(_) => {
+                eprintln!("tuiclock: unknown timezone '{name}'");
+                return Ok(());
+            }
+        }
+    } else {
+        Zone::Local
+    };
+
+    // One-shot export: render the current face to a file and exit without
+    // touching the terminal. Extension selects the format.
+    if let Some(path) = flag_value(&args, "--export") {
+        let time = zone.now();
+        let contents = if path.ends_with(".svg") {
+            export_svg(time, 200.0)
+        } else {
+            export_txt(Rect::new(0, 0, 80, 40), time)
+        };
+        std::fs::write(&path, contents)?;
+        return Ok(());
+    }
+
+    let mut timer = flag_value(&args, "--timer")
+        .and_then(|s| parse_duration(&s))
+        .map(|total| Timer {
+            start: Instant::now(),
+            total,
+            notified: false,
+        });
 
     enable_raw_mode()?;
     let mut stdout = stdout();
@@ -194,22 +626,106 @@ fn main() -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     loop {
+        // Fire the alarm exactly once when the countdown reaches zero.
+        if let Some(t) = timer.as_mut() {
+            if t.start.elapsed() >= t.total && !t.notified {
+                print!("\x07");
+                io::stdout().flush()?;
+                notify("tuiclock", "Timer finished");
+                t.notified = true;
+            }
+        }
+
+        // In stopwatch mode the face sweeps from elapsed time rather than the
+        // wall clock.
+        let time = match &stopwatch {
+            Some(sw) => elapsed_parts(sw.elapsed()),
+            None => zone.now(),
+        };
         terminal.draw(|f| {
-            let size = f.area();
-            let lines = draw_clock(size, use_color);
-            f.render_widget(Paragraph::new(lines), size);
+            let full = f.area();
+
+            // Stopwatch mode reserves a column on the right for the lap list.
+            let size = if let Some(sw) = &stopwatch {
+                let cols = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(0), Constraint::Length(18)])
+                    .split(full);
+                draw_stopwatch(f, cols[1], sw, use_color);
+                cols[0]
+            } else {
+                full
+            };
+
+            // Build the vertical layout: analog face on top, then the optional
+            // digital readout and countdown gauge stacked beneath it.
+            let mut constraints = vec![Constraint::Min(0)];
+            if digital {
+                constraints.push(Constraint::Length(4));
+            }
+            if timer.is_some() {
+                constraints.push(Constraint::Length(2));
+            }
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(constraints)
+                .split(size);
+
+            let lines = draw_clock(chunks[0], time, use_color);
+            f.render_widget(Paragraph::new(lines), chunks[0]);
+
+            let mut next = 1;
+            if digital {
+                draw_digital(f, chunks[next], time, twelve_hour, use_color);
+                next += 1;
+            }
+            if let Some(t) = &timer {
+                draw_timer(f, chunks[next], t, use_color);
+            }
         })?;
 
-        if event::poll(Duration::from_millis(16))?
-            && matches!(
-                event::read()?,
-                Event::Key(KeyEvent {
-                    code: KeyCode::Char('q'),
-                    ..
-                })
-            )
-        {
-            break;
+        // Sleep only until the next tick boundary rather than busy-looping:
+        // a smooth second hand wants 1/20 s steps, `--discrete` wants whole
+        // seconds. Cap the wait by a short input timeout so keys stay snappy.
+        let period_ns: u64 = if discrete {
+            1_000_000_000
+        } else {
+            1_000_000_000 / 20
+        };
+        let into_tick = time.3 as u64 % period_ns;
+        let until_next = Duration::from_nanos(period_ns - into_tick);
+        let timeout = until_next.min(Duration::from_millis(100));
+
+        if event::poll(timeout)? {
+            if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+                match code {
+                    KeyCode::Char('q') => break,
+                    // Toggle the large digital readout.
+                    KeyCode::Char('d') => digital = !digital,
+                    // Start/pause the stopwatch.
+                    KeyCode::Char(' ') => {
+                        if let Some(sw) = stopwatch.as_mut() {
+                            sw.toggle();
+                        }
+                    }
+                    // Record a lap split.
+                    KeyCode::Char('l') => {
+                        if let Some(sw) = stopwatch.as_mut() {
+                            sw.lap();
+                        }
+                    }
+                    // Reset the stopwatch, or restart the countdown.
+                    KeyCode::Char('r') => {
+                        if let Some(sw) = stopwatch.as_mut() {
+                            sw.reset();
+                        } else if let Some(t) = timer.as_mut() {
+                            t.start = Instant::now();
+                            t.notified = false;
+                        }
+                    }
+                    _ => {}
+                }
+            }
         }
     }
 